@@ -4,18 +4,171 @@ pub use crate::generator::types::TestConfig;
 use crate::generator::{
     templater::Templater,
     types::{CreateDefinition, FunctionCallDefinition, RpcProvider},
-    PlanConfig,
+    PlanConfig, RandSeed,
 };
 use crate::spammer::OnTxSent;
 use alloy::hex::ToHexExt;
-use alloy::primitives::{Address, TxHash};
+use alloy::network::{EthereumWallet, TransactionBuilder};
+use alloy::primitives::{Address, TxHash, U256};
+use alloy::providers::Provider;
+use alloy::signers::{
+    local::{coins_bip39::English, MnemonicBuilder, PrivateKeySigner},
+    Signer,
+};
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fs::read;
+use std::str::FromStr;
 use std::sync::Arc;
 use tokio::task::{spawn as spawn_task, JoinHandle};
 
 use super::NamedTxRequest;
 
+/// The BIP-44 path used to derive signers from [`WalletConfig::mnemonic`]: `m/44'/60'/0'/0/i`,
+/// the same derivation path used by Metamask and most other Ethereum wallets.
+const ACCOUNT_DERIVATION_PREFIX: &str = "m/44'/60'/0'/0";
+
+/// Optional `[wallets]` section of a `TestConfig`: derives `num_accounts` signers from a single
+/// mnemonic instead of requiring one `from` key per spam step, and optionally pre-funds each
+/// derived account from a master key before a run starts.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WalletConfig {
+    pub mnemonic: String,
+    pub num_accounts: u32,
+    /// Amount to send to each derived account before spamming, in wei. Skipped if `None`.
+    pub funding_amount: Option<String>,
+    /// Private key of the account that pays for pre-funding. Required if `funding_amount` is set.
+    /// Not part of the original (mnemonic, account count, funding amount) ask -- funding needs a
+    /// payer key from somewhere, so it's added here rather than assumed to be one of the derived
+    /// accounts.
+    pub funding_key: Option<String>,
+}
+
+impl WalletConfig {
+    /// Derives `self.num_accounts` signers from `self.mnemonic` along `m/44'/60'/0'/0/i` for
+    /// `i` in `0..num_accounts`.
+    pub fn derive_signers(&self) -> Result<Vec<PrivateKeySigner>, ContenderError> {
+        (0..self.num_accounts)
+            .map(|i| {
+                MnemonicBuilder::<English>::default()
+                    .phrase(&self.mnemonic)
+                    .derivation_path(format!("{ACCOUNT_DERIVATION_PREFIX}/{i}"))
+                    .map_err(|e| {
+                        ContenderError::SetupError(
+                            "invalid derivation path for mnemonic",
+                            Some(e.to_string()),
+                        )
+                    })?
+                    .build()
+                    .map_err(|e| {
+                        ContenderError::SetupError(
+                            "failed to derive signer from mnemonic",
+                            Some(e.to_string()),
+                        )
+                    })
+            })
+            .collect()
+    }
+
+    /// Sends `funding_amount` wei to every signer in `accounts` from `funding_key`. Sends are
+    /// submitted one after another (so the funding account's nonce increments correctly), but
+    /// confirmations are awaited concurrently -- waiting for "dozens or hundreds" of accounts to
+    /// land one block at a time would make provisioning a large fleet unusably slow.
+    pub async fn fund_accounts(
+        &self,
+        rpc_provider: &RpcProvider,
+        accounts: &[PrivateKeySigner],
+    ) -> Result<(), ContenderError> {
+        let amount = self
+            .funding_amount
+            .as_deref()
+            .ok_or(ContenderError::SetupError(
+                "no funding_amount configured",
+                None,
+            ))?;
+        let amount = U256::from_str_radix(
+            amount.trim_start_matches("0x"),
+            if amount.starts_with("0x") { 16 } else { 10 },
+        )
+        .map_err(|e| ContenderError::SetupError("invalid funding_amount", Some(e.to_string())))?;
+        let funding_key = self
+            .funding_key
+            .as_deref()
+            .ok_or(ContenderError::SetupError(
+                "no funding_key configured",
+                None,
+            ))?;
+        let funding_signer = PrivateKeySigner::from_str(funding_key)
+            .map_err(|e| ContenderError::SetupError("invalid funding_key", Some(e.to_string())))?;
+        let funding_address = funding_signer.address();
+        let funding_wallet = EthereumWallet::from(funding_signer);
+
+        // `tx.build(&funding_wallet)` signs locally and never touches the network, so every field
+        // a filling provider would normally supply has to be set by hand first: chain_id, fees,
+        // gas_limit (a plain ETH transfer is always exactly 21000 gas), and a nonce we increment
+        // ourselves as each send goes out, since these sends are submitted one after another
+        // specifically so the nonce sequence stays correct.
+        let chain_id = rpc_provider.get_chain_id().await.map_err(|e| {
+            ContenderError::SetupError("failed to fetch chain id", Some(e.to_string()))
+        })?;
+        let fees = rpc_provider
+            .estimate_eip1559_fees(None)
+            .await
+            .map_err(|e| {
+                ContenderError::SetupError("failed to estimate gas fees", Some(e.to_string()))
+            })?;
+        let mut nonce = rpc_provider
+            .get_transaction_count(funding_address)
+            .await
+            .map_err(|e| {
+                ContenderError::SetupError(
+                    "failed to fetch funding account nonce",
+                    Some(e.to_string()),
+                )
+            })?;
+
+        let mut pending_txs = Vec::with_capacity(accounts.len());
+        for account in accounts {
+            let tx = alloy::rpc::types::TransactionRequest::default()
+                .with_to(account.address())
+                .with_value(amount)
+                .with_chain_id(chain_id)
+                .with_nonce(nonce)
+                .with_gas_limit(21_000)
+                .with_max_fee_per_gas(fees.max_fee_per_gas)
+                .with_max_priority_fee_per_gas(fees.max_priority_fee_per_gas);
+            let envelope = tx.build(&funding_wallet).await.map_err(|e| {
+                ContenderError::SetupError("failed to sign funding tx", Some(e.to_string()))
+            })?;
+            let pending = rpc_provider.send_tx_envelope(envelope).await.map_err(|e| {
+                ContenderError::SetupError("failed to send funding tx", Some(e.to_string()))
+            })?;
+            pending_txs.push(pending);
+            nonce += 1;
+        }
+
+        // spawn every confirmation wait up front so they run concurrently, then await them
+        let confirmations = pending_txs
+            .into_iter()
+            .map(|pending| spawn_task(async move { pending.get_receipt().await }))
+            .collect::<Vec<_>>();
+        for confirmation in confirmations {
+            confirmation
+                .await
+                .map_err(|e| {
+                    ContenderError::SetupError(
+                        "funding confirmation task panicked",
+                        Some(e.to_string()),
+                    )
+                })?
+                .map_err(|e| {
+                    ContenderError::SetupError("funding tx did not confirm", Some(e.to_string()))
+                })?;
+        }
+        Ok(())
+    }
+}
+
 impl TestConfig {
     pub fn from_file(file_path: &str) -> Result<TestConfig, Box<dyn std::error::Error>> {
         let file_contents = read(file_path)?;
@@ -34,6 +187,127 @@ impl TestConfig {
         std::fs::write(file_path, encoded)?;
         Ok(())
     }
+
+    /// Derives the `from` signer pool from `self.wallets`, if a `[wallets]` section is present.
+    pub fn derive_wallets(&self) -> Result<Option<Vec<PrivateKeySigner>>, ContenderError> {
+        self.wallets
+            .as_ref()
+            .map(WalletConfig::derive_signers)
+            .transpose()
+    }
+
+    /// The signer pool a `TestScenario` should be built with: `self.wallets`'s derived signers if
+    /// a `[wallets]` section is configured, `fallback` otherwise. Callers constructing a
+    /// `TestScenario` for this config should pass `self.signer_pool(&fallback)?` as its `from` set
+    /// instead of reaching for `derive_wallets` (or a hardcoded list) directly, so a configured
+    /// `[wallets]` section actually supplies the run's signers rather than just being available to
+    /// call.
+    pub fn signer_pool(
+        &self,
+        fallback: &[PrivateKeySigner],
+    ) -> Result<Vec<PrivateKeySigner>, ContenderError> {
+        Ok(self.derive_wallets()?.unwrap_or_else(|| fallback.to_vec()))
+    }
+
+    /// Funds the accounts derived from `self.wallets` via `WalletConfig::fund_accounts`, if a
+    /// `[wallets]` section with `funding_amount` is present. A no-op if there's no `[wallets]`
+    /// section or it has no `funding_amount`; returns an error if `funding_amount` is set without
+    /// `funding_key`.
+    pub async fn fund_wallets(&self, rpc_provider: &RpcProvider) -> Result<(), ContenderError> {
+        let Some(wallets) = self.wallets.as_ref() else {
+            return Ok(());
+        };
+        if wallets.funding_amount.is_none() {
+            return Ok(());
+        }
+        let accounts = wallets.derive_signers()?;
+        wallets.fund_accounts(rpc_provider, &accounts).await
+    }
+
+    /// Sanity-checks a config before it's adopted as the active plan: a config with no create,
+    /// setup, or spam steps at all can't do anything, so it's rejected rather than silently
+    /// swapped in.
+    pub fn validate(&self) -> Result<(), ContenderError> {
+        if self.create.is_none() && self.setup.is_none() && self.spam.is_none() {
+            return Err(ContenderError::SetupError(
+                "config has no create, setup, or spam steps",
+                None,
+            ));
+        }
+        Ok(())
+    }
+
+    /// Watches `file_path` for changes and keeps a validated, live-reloadable copy of it behind
+    /// the returned [`ConfigWatchHandle`]. A `TestScenario` that wants hot-reload holds onto
+    /// `handle.config` and reads it at the top of each spam iteration (never mid-step), so a
+    /// reload can never tear an in-flight step in half. Invalid reloads (bad TOML, or a config
+    /// that fails [`TestConfig::validate`]) are logged and discarded, leaving the previously
+    /// active config in force.
+    pub fn watch_file(
+        file_path: &str,
+        poll_interval: std::time::Duration,
+    ) -> Result<ConfigWatchHandle, ContenderError> {
+        let mut last_modified = std::fs::metadata(file_path).and_then(|m| m.modified()).ok();
+        let initial = Self::from_file(file_path).map_err(|e| {
+            ContenderError::SetupError("failed to load config", Some(e.to_string()))
+        })?;
+        initial.validate()?;
+
+        let config = Arc::new(tokio::sync::RwLock::new(initial));
+        let watched_config = config.clone();
+        let path = file_path.to_owned();
+        let task = spawn_task(async move {
+            loop {
+                tokio::time::sleep(poll_interval).await;
+
+                let modified = match std::fs::metadata(&path).and_then(|m| m.modified()) {
+                    Ok(modified) => modified,
+                    Err(_) => continue,
+                };
+                if Some(modified) == last_modified {
+                    continue;
+                }
+                last_modified = Some(modified);
+
+                match Self::from_file(&path)
+                    .map_err(|e| e.to_string())
+                    .and_then(|cfg| {
+                        cfg.validate().map_err(|e| e.to_string())?;
+                        Ok(cfg)
+                    }) {
+                    Ok(new_config) => *watched_config.write().await = new_config,
+                    Err(e) => eprintln!("rejected config reload from {path}: {e}"),
+                }
+            }
+        });
+
+        Ok(ConfigWatchHandle { config, task })
+    }
+}
+
+/// Handle returned by [`TestConfig::watch_file`]. `config` is the live, atomically-swapped
+/// config; drop or call [`ConfigWatchHandle::stop`] to stop watching.
+pub struct ConfigWatchHandle {
+    pub config: Arc<tokio::sync::RwLock<TestConfig>>,
+    task: JoinHandle<()>,
+}
+
+impl ConfigWatchHandle {
+    /// Returns a clone of the currently active config.
+    pub async fn current(&self) -> TestConfig {
+        self.config.read().await.clone()
+    }
+
+    /// Stops watching the file. The last successfully loaded config remains in `self.config`.
+    pub fn stop(self) {
+        self.task.abort();
+    }
+}
+
+impl Drop for ConfigWatchHandle {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
 }
 
 impl PlanConfig<String> for TestConfig {
@@ -63,16 +337,287 @@ impl PlanConfig<String> for TestConfig {
     }
 }
 
+/// A typed cast applied to a placeholder's resolved value before it's spliced into a template
+/// string, so e.g. `{int:block_number}` re-emits a canonical decimal integer instead of whatever
+/// literal string happened to be stored in the template map.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Conversion {
+    Bytes,
+    Integer,
+    Float,
+    Boolean,
+    Timestamp,
+    TimestampFmt,
+}
+
+impl Conversion {
+    /// Maps a Solidity-ish ABI type name (the argument to `{random:<type>}`) to the conversion
+    /// used to format the generated value.
+    fn from_abi_type(ty: &str) -> Self {
+        if ty == "bool" {
+            Self::Boolean
+        } else if ty == "address" || ty.starts_with("bytes") {
+            Self::Bytes
+        } else {
+            // uint*/int* and anything unrecognized fall back to a plain integer
+            Self::Integer
+        }
+    }
+}
+
+impl FromStr for Conversion {
+    type Err = ContenderError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "bytes" => Ok(Self::Bytes),
+            "int" => Ok(Self::Integer),
+            "float" => Ok(Self::Float),
+            "bool" => Ok(Self::Boolean),
+            "now" => Ok(Self::Timestamp),
+            "timestamp" => Ok(Self::TimestampFmt),
+            other => Err(ContenderError::SpamError(
+                "unrecognized placeholder conversion",
+                Some(other.to_owned()),
+            )),
+        }
+    }
+}
+
+/// Parses a `+30s` / `-10m` / `1h` style offset into signed seconds. An empty or unparseable
+/// string is treated as a zero offset.
+fn parse_offset_seconds(arg: &str) -> i64 {
+    let arg = arg.trim();
+    if arg.is_empty() {
+        return 0;
+    }
+    let (sign, rest) = match arg.strip_prefix('-') {
+        Some(rest) => (-1, rest),
+        None => (1, arg.strip_prefix('+').unwrap_or(arg)),
+    };
+    let (digits, unit) = rest.split_at(
+        rest.find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len()),
+    );
+    let value: i64 = digits.parse().unwrap_or(0);
+    let multiplier = match unit {
+        "" | "s" => 1,
+        "m" => 60,
+        "h" => 3600,
+        "d" => 86_400,
+        _ => 1,
+    };
+    sign * value * multiplier
+}
+
+/// Unix seconds to (year, month, day, hour, minute, second), with the date part computed via
+/// Howard Hinnant's `civil_from_days` algorithm.
+/// Lets us render `{timestamp:date}` without pulling in a chrono dependency.
+fn civil_from_unix(secs: u64) -> (i64, u32, u32, u32, u32, u32) {
+    let days = (secs / 86_400) as i64;
+    let rem = secs % 86_400;
+    let (hour, minute, second) = (
+        (rem / 3600) as u32,
+        ((rem / 60) % 60) as u32,
+        (rem % 60) as u32,
+    );
+
+    let z = days + 719_468;
+    let era = if z >= 0 { z } else { z - 146_096 } / 146_097;
+    let doe = (z - era * 146_097) as u64;
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let y = yoe as i64 + era * 400;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let d = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let m = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let y = if m <= 2 { y + 1 } else { y };
+
+    (y, m, d, hour, minute, second)
+}
+
+/// A zero seed used only where no real [`RandSeed`] is available (the `Templater` trait
+/// signature doesn't carry one -- see the note on `find_key` below). `{random:...}` resolved
+/// through this path is NOT tied to the run's seed; real spam-arg generation should go through
+/// [`TestConfig::replace_placeholders_with_seed`] instead.
+const UNSEEDED_RANDOM: [u8; 32] = [0u8; 32];
+
+impl TestConfig {
+    /// Resolves a single `{token}` placeholder against an explicit seed. `token` is the text
+    /// between the braces, not including them. Tokens of the form `function:arg` dispatch
+    /// through [`Conversion::from_str`] (`now`, `int`, `float`, `bool`, `bytes`) or the `random`
+    /// built-in; `now` and `timestamp` may also appear bare (`{now}`, no arg). Anything else falls
+    /// back to a literal lookup in `template_map`, matching the original substitution behavior.
+    fn resolve_placeholder(
+        &self,
+        token: &str,
+        template_map: &HashMap<String, String>,
+        occurrence: usize,
+        seed: &[u8],
+    ) -> String {
+        let (function, arg) = match token.split_once(':') {
+            Some((function, arg)) => (function, arg),
+            // bare builtins take no arg -- `{now}` is `{now:}`, `{timestamp}` is `{timestamp:}`.
+            // anything else with no colon is a plain literal key, same as before this branch
+            // existed.
+            None if token == "now" => return Self::format_now_offset(""),
+            None if token == "timestamp" => return Self::format_now_fmt(""),
+            None => return Self::resolve_literal(token, template_map),
+        };
+
+        if function == "random" {
+            return Self::format_random(arg, seed, occurrence);
+        }
+
+        match Conversion::from_str(function) {
+            Ok(Conversion::Timestamp) => Self::format_now_offset(arg),
+            Ok(Conversion::TimestampFmt) => Self::format_now_fmt(arg),
+            Ok(Conversion::Integer) => match template_map.get(arg) {
+                Some(raw) => Self::format_int(raw),
+                None => format!("{{{token}}}"),
+            },
+            Ok(Conversion::Float) => match template_map.get(arg) {
+                Some(raw) => raw
+                    .parse::<f64>()
+                    .map(|v| v.to_string())
+                    .unwrap_or_else(|_| raw.to_owned()),
+                None => format!("{{{token}}}"),
+            },
+            Ok(Conversion::Boolean) => match template_map.get(arg) {
+                Some(raw) => match raw.as_str() {
+                    "1" | "true" | "0x1" => "true".to_owned(),
+                    _ => "false".to_owned(),
+                },
+                None => format!("{{{token}}}"),
+            },
+            Ok(Conversion::Bytes) => match template_map.get(arg) {
+                Some(raw) if raw.starts_with("0x") => raw.to_owned(),
+                Some(raw) => format!("0x{raw}"),
+                None => format!("{{{token}}}"),
+            },
+            // not a recognized function name -- treat the whole token as a literal key,
+            // same as a bare `{key}` placeholder
+            Err(_) => Self::resolve_literal(token, template_map),
+        }
+    }
+
+    /// Literal `{key}` lookup: returns the mapped value, or the original `{token}` text
+    /// unchanged if `key` isn't present -- matches the original substitution behavior.
+    fn resolve_literal(token: &str, template_map: &HashMap<String, String>) -> String {
+        template_map
+            .get(token)
+            .cloned()
+            .unwrap_or_else(|| format!("{{{token}}}"))
+    }
+
+    /// `{now}` / `{now:+30s}` -- current unix time, optionally offset.
+    fn format_now_offset(arg: &str) -> String {
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .expect("system time is before the unix epoch")
+            .as_secs();
+        (now as i64 + parse_offset_seconds(arg)).to_string()
+    }
+
+    /// `{timestamp:date}` / `{timestamp}` -- current unix time rendered as a calendar date, or
+    /// RFC3339 by default.
+    fn format_now_fmt(arg: &str) -> String {
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .expect("system time is before the unix epoch")
+            .as_secs();
+        let (y, mo, d, h, mi, s) = civil_from_unix(now);
+        match arg {
+            "date" => format!("{y:04}-{mo:02}-{d:02}"),
+            _ => format!("{y:04}-{mo:02}-{d:02}T{h:02}:{mi:02}:{s:02}Z"),
+        }
+    }
+
+    /// `{random:<abi_type>}` -- a value deterministically derived from `seed`, `arg` (the ABI
+    /// type name), and `occurrence` (this placeholder's position in the input), formatted
+    /// per [`Conversion::from_abi_type`].
+    fn format_random(arg: &str, seed: &[u8], occurrence: usize) -> String {
+        let mut preimage = seed.to_vec();
+        preimage.extend_from_slice(arg.as_bytes());
+        preimage.extend_from_slice(&occurrence.to_be_bytes());
+        let digest = alloy::primitives::keccak256(&preimage);
+        match Conversion::from_abi_type(arg) {
+            Conversion::Boolean => {
+                if digest[0] & 1 == 1 {
+                    "true".to_owned()
+                } else {
+                    "false".to_owned()
+                }
+            }
+            Conversion::Bytes => format!("0x{}", digest.encode_hex()),
+            _ => U256::from_be_bytes(digest.0).to_string(),
+        }
+    }
+
+    /// Re-emits an integer template value (decimal or `0x`-hex) as a canonical decimal string.
+    fn format_int(raw: &str) -> String {
+        let (digits, radix) = match raw.strip_prefix("0x") {
+            Some(hex) => (hex, 16),
+            None => (raw, 10),
+        };
+        U256::from_str_radix(digits, radix)
+            .map(|v| v.to_string())
+            .unwrap_or_else(|_| raw.to_owned())
+    }
+
+    /// Like [`Templater::replace_placeholders`], but resolves `{random:...}` deterministically
+    /// from `seed` rather than an unseeded default. `TestScenario`/`Generator` should call this
+    /// (not the trait method) when building spam args for a run, so fuzzed/randomized args stay
+    /// reproducible across runs sharing a `RandSeed`, the same way `fuzz_is_deterministic`
+    /// expects fuzzed args to be.
+    pub fn replace_placeholders_with_seed(
+        &self,
+        input: &str,
+        template_map: &HashMap<String, String>,
+        seed: &RandSeed,
+    ) -> String {
+        self.replace_placeholders_inner(input, template_map, seed.as_bytes())
+    }
+
+    fn replace_placeholders_inner(
+        &self,
+        input: &str,
+        template_map: &HashMap<String, String>,
+        seed: &[u8],
+    ) -> String {
+        let mut output = String::with_capacity(input.len());
+        let mut rest = input;
+        let mut occurrence = 0usize;
+        while let Some(start) = rest.find('{') {
+            let Some(end) = rest[start..].find('}').map(|e| start + e) else {
+                output.push_str(rest);
+                return output;
+            };
+            output.push_str(&rest[..start]);
+            output.push_str(&self.resolve_placeholder(
+                &rest[start + 1..end],
+                template_map,
+                occurrence,
+                seed,
+            ));
+            occurrence += 1;
+            rest = &rest[end + 1..];
+        }
+        output.push_str(rest);
+        output
+    }
+}
+
 impl Templater<String> for TestConfig {
     /// Find values wrapped in brackets in a string and replace them with values from a hashmap whose key match the value in the brackets.
     /// example: "hello {world}" with hashmap {"world": "earth"} will return "hello earth"
+    /// Brackets may also carry a typed conversion/function, e.g. "{now}", "{now:+30s}",
+    /// "{int:block_number}", or "{random:uint256}" -- see [`TestConfig::resolve_placeholder`].
+    /// `{random:...}` resolved through this trait method is unseeded (see
+    /// [`TestConfig::replace_placeholders_with_seed`] for the seeded path real spam-arg
+    /// generation should use).
     fn replace_placeholders(&self, input: &str, template_map: &HashMap<String, String>) -> String {
-        let mut output = input.to_owned();
-        for (key, value) in template_map.iter() {
-            let template = format!("{{{}}}", key);
-            output = output.replace(&template, value);
-        }
-        output
+        self.replace_placeholders_inner(input, template_map, &UNSEEDED_RANDOM)
     }
 
     fn terminator_start(&self, input: &str) -> Option<usize> {
@@ -91,15 +636,30 @@ impl Templater<String> for TestConfig {
         input.split_at(last_end).1.to_owned()
     }
 
+    // NOTE: this and the other granular scanning primitives above (`terminator_start/end`,
+    // `num_placeholders`, `copy_end`) only see a raw substring -- they have no `template_map` and
+    // no `RandSeed`, so they can't evaluate `int`/`float`/`bool`/`bytes`/`random` functions
+    // themselves. `now`/`timestamp` need neither, so those resolve here directly; anything else
+    // is returned as a literal key, same as before this change. If `Generator` walks placeholders
+    // with these primitives (rather than calling `replace_placeholders`/
+    // `replace_placeholders_with_seed`) for spam-arg substitution, the typed-conversion and
+    // `random` placeholders will only resolve once that caller is updated to treat a `function:arg`
+    // key it doesn't recognize as "ask `TestConfig::resolve_placeholder`", not as a missing map
+    // entry -- that change lives in `Generator`, outside this module.
     fn find_key(&self, input: &str) -> Option<(String, usize)> {
-        if let Some(template_start) = self.terminator_start(input) {
-            let template_end = self.terminator_end(input);
-            if let Some(template_end) = template_end {
-                let template_name = &input[template_start + 1..template_end];
-                return Some((template_name.to_owned(), template_end));
-            }
-        }
-        None
+        let template_start = self.terminator_start(input)?;
+        let template_end = self.terminator_end(input)?;
+        let token = &input[template_start + 1..template_end];
+
+        let resolved = match token.split_once(':') {
+            Some(("now", arg)) => Some(Self::format_now_offset(arg)),
+            Some(("timestamp", arg)) => Some(Self::format_now_fmt(arg)),
+            None if token == "now" => Some(Self::format_now_offset("")),
+            None if token == "timestamp" => Some(Self::format_now_fmt("")),
+            _ => None,
+        };
+
+        Some((resolved.unwrap_or_else(|| token.to_owned()), template_end))
     }
 
     fn encode_contract_address(&self, input: &Address) -> String {
@@ -166,9 +726,447 @@ where
     }
 }
 
+/// Outcome of a single send/confirm attempt, as recorded by [`ConfirmingCallback`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TxStatus {
+    /// Sent but not yet confirmed or replaced.
+    Pending,
+    /// A receipt was found for this tx hash.
+    Included,
+    /// No receipt appeared before the timeout; a fee-bumped replacement was sent.
+    Replaced,
+    /// Retries were exhausted with no receipt for any attempt.
+    Dropped,
+}
+
+/// Bumps an EIP-1559 fee field by the minimum 12.5% required for a same-nonce replacement
+/// to be accepted by the mempool, rounding up so a 1 wei fee still increases.
+fn bump_eip1559_fee(fee: u128) -> u128 {
+    fee + (fee + 7) / 8
+}
+
+/// Polls for a receipt until one appears or `confirmation_timeout` elapses, backing off
+/// exponentially from `poll_interval` between attempts (capped at the timeout itself). Shared by
+/// [`ConfirmingCallback`] (which resubmits on timeout) and [`MetricsCallback`] (which just needs
+/// to classify the outcome), so both measure against the same "did it land in time" definition.
+async fn wait_for_receipt(
+    rpc_provider: &RpcProvider,
+    tx_hash: TxHash,
+    poll_interval: std::time::Duration,
+    confirmation_timeout: std::time::Duration,
+) -> Option<alloy::rpc::types::TransactionReceipt> {
+    let deadline = std::time::Instant::now() + confirmation_timeout;
+    let mut backoff = poll_interval;
+    loop {
+        if let Ok(Some(receipt)) = rpc_provider.get_transaction_receipt(tx_hash).await {
+            return Some(receipt);
+        }
+        if std::time::Instant::now() >= deadline {
+            return None;
+        }
+        tokio::time::sleep(backoff.min(confirmation_timeout)).await;
+        backoff *= 2;
+    }
+}
+
+/// `OnTxSent` implementor that tracks each spam tx through to inclusion, resubmitting with a
+/// bumped fee (same nonce) if it doesn't land before `confirmation_timeout`, up to `max_retries`
+/// times. Mirrors the "send and confirm" behavior other chains' clients provide out of the box:
+/// receipt polling plus fee-bumped resubmission of a tx that doesn't land.
+pub struct ConfirmingCallback<D> {
+    pub db: Arc<D>,
+    pub rpc_provider: Arc<RpcProvider>,
+    pub signers: HashMap<Address, PrivateKeySigner>,
+    pub poll_interval: std::time::Duration,
+    pub confirmation_timeout: std::time::Duration,
+    pub max_retries: usize,
+}
+
+impl<D> ConfirmingCallback<D>
+where
+    D: DbOps + Send + Sync + 'static,
+{
+    pub fn new(db: Arc<D>, rpc_provider: Arc<RpcProvider>, signers: &[PrivateKeySigner]) -> Self {
+        Self {
+            db,
+            rpc_provider,
+            signers: signers.iter().map(|s| (s.address(), s.clone())).collect(),
+            poll_interval: std::time::Duration::from_millis(500),
+            confirmation_timeout: std::time::Duration::from_secs(12),
+            max_retries: 5,
+        }
+    }
+
+    /// Keeps the original request's nonce (the sender's nonce is not re-fetched), bumps both
+    /// EIP-1559 fee fields by the minimum valid replacement bump, re-signs from the original
+    /// request, and resubmits.
+    async fn resubmit_with_higher_fee(
+        rpc_provider: &RpcProvider,
+        signers: &HashMap<Address, PrivateKeySigner>,
+        tx_req: &mut NamedTxRequest,
+    ) -> Result<TxHash, ContenderError> {
+        let from = tx_req.tx.from.ok_or(ContenderError::SpamError(
+            "tx request has no from address",
+            None,
+        ))?;
+        let signer = signers.get(&from).ok_or(ContenderError::SpamError(
+            "no signer available for sender",
+            None,
+        ))?;
+
+        // reuse the original tx's own nonce -- a replacement is only valid if it keeps the same
+        // nonce as the tx it's replacing. Re-deriving the nonce from the latest confirmed count
+        // would instead target the sender's lowest unconfirmed nonce, colliding with whichever of
+        // its other in-flight txs happens to sit there rather than the one that actually timed out.
+        if let Some(max_fee) = tx_req.tx.max_fee_per_gas {
+            tx_req.tx.set_max_fee_per_gas(bump_eip1559_fee(max_fee));
+        }
+        if let Some(priority_fee) = tx_req.tx.max_priority_fee_per_gas {
+            tx_req
+                .tx
+                .set_max_priority_fee_per_gas(bump_eip1559_fee(priority_fee));
+        }
+
+        let wallet = EthereumWallet::from(signer.clone());
+        let envelope = tx_req.tx.clone().build(&wallet).await.map_err(|e| {
+            ContenderError::SpamError("failed to re-sign replacement tx", Some(e.to_string()))
+        })?;
+        let pending = rpc_provider.send_tx_envelope(envelope).await.map_err(|e| {
+            ContenderError::SpamError("failed to resubmit replacement tx", Some(e.to_string()))
+        })?;
+        Ok(*pending.tx_hash())
+    }
+}
+
+impl<D> OnTxSent for ConfirmingCallback<D>
+where
+    D: DbOps + Send + Sync + 'static,
+{
+    fn on_tx_sent(
+        &self,
+        tx_hash: TxHash,
+        req: NamedTxRequest,
+        extra: Option<HashMap<String, String>>,
+    ) -> Option<JoinHandle<()>> {
+        let db = self.db.clone();
+        let rpc_provider = self.rpc_provider.clone();
+        let signers = self.signers.clone();
+        let poll_interval = self.poll_interval;
+        let confirmation_timeout = self.confirmation_timeout;
+        let max_retries = self.max_retries;
+        let run_id = extra
+            .map(|e| e.get("run_id").unwrap().parse::<u64>().unwrap())
+            .unwrap_or(0);
+
+        let handle = spawn_task(async move {
+            let mut tx_hash = tx_hash;
+            let mut tx_req = req;
+
+            for attempt in 0..=max_retries {
+                match wait_for_receipt(&rpc_provider, tx_hash, poll_interval, confirmation_timeout)
+                    .await
+                {
+                    Some(receipt) => {
+                        db.insert_tx_attempt(
+                            run_id,
+                            tx_hash,
+                            TxStatus::Included,
+                            receipt.block_number,
+                            Some(receipt.gas_used as u64),
+                        )
+                        .expect("failed to insert tx attempt into db");
+                        return;
+                    }
+                    None => {
+                        // no separate Pending row here -- it would be immediately followed by the
+                        // Replaced/Dropped row below for the same tx_hash, so it'd only inflate
+                        // aggregate_persisted_metrics's per-row `sent` count without adding
+                        // information a reader couldn't already get from the terminal row.
+                        if attempt == max_retries {
+                            db.insert_tx_attempt(run_id, tx_hash, TxStatus::Dropped, None, None)
+                                .expect("failed to insert tx attempt into db");
+                            return;
+                        }
+                        match Self::resubmit_with_higher_fee(&rpc_provider, &signers, &mut tx_req)
+                            .await
+                        {
+                            Ok(new_hash) => {
+                                db.insert_tx_attempt(
+                                    run_id,
+                                    tx_hash,
+                                    TxStatus::Replaced,
+                                    None,
+                                    None,
+                                )
+                                .expect("failed to insert tx attempt into db");
+                                tx_hash = new_hash;
+                            }
+                            Err(_) => {
+                                db.insert_tx_attempt(
+                                    run_id,
+                                    tx_hash,
+                                    TxStatus::Dropped,
+                                    None,
+                                    None,
+                                )
+                                .expect("failed to insert tx attempt into db");
+                                return;
+                            }
+                        }
+                    }
+                }
+            }
+        });
+        Some(handle)
+    }
+}
+
+/// Atomic per-run counters and inclusion-latency samples. Populated live by [`MetricsCallback`]
+/// as each tx is classified, or after the fact by [`aggregate_persisted_metrics`] from `DbOps`'s
+/// durable record -- same fields, same query methods below, either source.
+#[derive(Default)]
+pub struct RunMetrics {
+    pub sent: std::sync::atomic::AtomicU64,
+    pub confirmed: std::sync::atomic::AtomicU64,
+    pub reverted: std::sync::atomic::AtomicU64,
+    pub replaced: std::sync::atomic::AtomicU64,
+    pub dropped: std::sync::atomic::AtomicU64,
+    inclusion_latencies_ms: std::sync::Mutex<Vec<u64>>,
+}
+
+impl RunMetrics {
+    fn record_latency_ms(&self, latency_ms: u64) {
+        self.inclusion_latencies_ms
+            .lock()
+            .expect("metrics lock poisoned")
+            .push(latency_ms);
+    }
+
+    /// Sent transactions per second over `elapsed`.
+    pub fn throughput_tx_per_sec(&self, elapsed: std::time::Duration) -> f64 {
+        let sent = self.sent.load(std::sync::atomic::Ordering::Relaxed) as f64;
+        let secs = elapsed.as_secs_f64();
+        if secs <= 0.0 {
+            0.0
+        } else {
+            sent / secs
+        }
+    }
+
+    /// Fraction of sent transactions that were confirmed (not dropped or reverted).
+    pub fn success_rate(&self) -> f64 {
+        let sent = self.sent.load(std::sync::atomic::Ordering::Relaxed) as f64;
+        let confirmed = self.confirmed.load(std::sync::atomic::Ordering::Relaxed) as f64;
+        if sent == 0.0 {
+            0.0
+        } else {
+            confirmed / sent
+        }
+    }
+
+    /// The `p`-th percentile (0.0..=1.0) send->inclusion latency in milliseconds, or `None` if
+    /// no inclusions have been recorded yet.
+    pub fn latency_percentile_ms(&self, p: f64) -> Option<u64> {
+        let mut latencies = self
+            .inclusion_latencies_ms
+            .lock()
+            .expect("metrics lock poisoned")
+            .clone();
+        if latencies.is_empty() {
+            return None;
+        }
+        latencies.sort_unstable();
+        let idx = (((latencies.len() - 1) as f64) * p.clamp(0.0, 1.0)).round() as usize;
+        latencies.get(idx).copied()
+    }
+
+    /// Renders the counters as Prometheus text-exposition-format lines for `run_id`.
+    pub fn prometheus_snapshot(&self, run_id: u64) -> String {
+        let o = std::sync::atomic::Ordering::Relaxed;
+        format!(
+            "contender_tx_sent_total{{run_id=\"{run_id}\"}} {}\n\
+             contender_tx_confirmed_total{{run_id=\"{run_id}\"}} {}\n\
+             contender_tx_reverted_total{{run_id=\"{run_id}\"}} {}\n\
+             contender_tx_replaced_total{{run_id=\"{run_id}\"}} {}\n\
+             contender_tx_dropped_total{{run_id=\"{run_id}\"}} {}\n",
+            self.sent.load(o),
+            self.confirmed.load(o),
+            self.reverted.load(o),
+            self.replaced.load(o),
+            self.dropped.load(o),
+        )
+    }
+}
+
+/// `OnTxSent` implementor that keeps atomic send/confirm/revert/replace/drop counters and
+/// inclusion-latency samples per `run_id`, in addition to writing the same row `LogCallback`
+/// does. Unlike `LogCallback`, a failed DB insert is counted as `dropped` and logged rather than
+/// panicking the spawned task; a tx with no receipt by `confirmation_timeout` (including one whose
+/// receipt lookups all failed with RPC errors) is counted as `dropped` without being logged.
+///
+/// `MetricsCallback` doesn't retry or resubmit a tx itself (unlike [`ConfirmingCallback`]), so its
+/// own `replaced` counter always stays at 0 -- nothing it does can produce a replacement. Run
+/// [`ConfirmingCallback`] alongside it (or instead of it) and read `replaced` back from
+/// [`aggregate_persisted_metrics`] once replacements are wired into a `DbOps` read path; see that
+/// function's doc for why the read side isn't implemented here yet.
+pub struct MetricsCallback<D> {
+    pub db: Arc<D>,
+    pub rpc_provider: Arc<RpcProvider>,
+    pub poll_interval: std::time::Duration,
+    pub confirmation_timeout: std::time::Duration,
+    metrics: std::sync::Mutex<HashMap<u64, Arc<RunMetrics>>>,
+}
+
+impl<D> MetricsCallback<D>
+where
+    D: DbOps + Send + Sync + 'static,
+{
+    pub fn new(db: Arc<D>, rpc_provider: Arc<RpcProvider>) -> Self {
+        Self {
+            db,
+            rpc_provider,
+            poll_interval: std::time::Duration::from_millis(500),
+            confirmation_timeout: std::time::Duration::from_secs(12),
+            metrics: std::sync::Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Returns the (possibly freshly-created) metrics for `run_id`.
+    pub fn metrics_for_run(&self, run_id: u64) -> Arc<RunMetrics> {
+        self.metrics
+            .lock()
+            .expect("metrics lock poisoned")
+            .entry(run_id)
+            .or_default()
+            .clone()
+    }
+}
+
+impl<D> OnTxSent for MetricsCallback<D>
+where
+    D: DbOps + Send + Sync + 'static,
+{
+    fn on_tx_sent(
+        &self,
+        tx_hash: TxHash,
+        _req: NamedTxRequest,
+        extra: Option<HashMap<String, String>>,
+    ) -> Option<JoinHandle<()>> {
+        let run_id = extra
+            .as_ref()
+            .map(|e| e.get("run_id").unwrap().parse::<u64>().unwrap())
+            .unwrap_or(0);
+        let metrics = self.metrics_for_run(run_id);
+        metrics
+            .sent
+            .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+
+        let db = self.db.clone();
+        let rpc_provider = self.rpc_provider.clone();
+        let poll_interval = self.poll_interval;
+        let confirmation_timeout = self.confirmation_timeout;
+        let sent_at_ms = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .expect("failed to get timestamp")
+            .as_millis() as u64;
+
+        let handle = spawn_task(async move {
+            if let Err(e) = db.insert_run_tx(run_id, tx_hash, sent_at_ms as usize) {
+                metrics
+                    .dropped
+                    .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+                eprintln!("failed to record sent tx {tx_hash}: {e}");
+                return;
+            }
+
+            match wait_for_receipt(&rpc_provider, tx_hash, poll_interval, confirmation_timeout)
+                .await
+            {
+                Some(receipt) => {
+                    // mutually exclusive: a reverted tx is not also a success, so it must not
+                    // land in `confirmed` (success_rate = confirmed / sent would otherwise count
+                    // it as a success).
+                    if receipt.status() {
+                        metrics
+                            .confirmed
+                            .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+                    } else {
+                        metrics
+                            .reverted
+                            .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+                    }
+                    let included_at_ms = std::time::SystemTime::now()
+                        .duration_since(std::time::UNIX_EPOCH)
+                        .expect("failed to get timestamp")
+                        .as_millis() as u64;
+                    metrics.record_latency_ms(included_at_ms.saturating_sub(sent_at_ms));
+                }
+                None => {
+                    metrics
+                        .dropped
+                        .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+                }
+            }
+        });
+        Some(handle)
+    }
+}
+
+/// One persisted send/confirm attempt for a run, matching what [`ConfirmingCallback`] and
+/// [`MetricsCallback`] write via `DbOps::insert_run_tx`/`insert_tx_attempt`.
+#[derive(Debug, Clone, Copy)]
+pub struct PersistedTxAttempt {
+    pub tx_hash: TxHash,
+    pub status: TxStatus,
+    pub sent_at_ms: Option<u64>,
+    pub included_at_ms: Option<u64>,
+}
+
+/// Aggregates `attempts` -- everything `DbOps` has persisted for one run -- into a [`RunMetrics`],
+/// the same counters/latencies `MetricsCallback` would have produced live, but computed from the
+/// durable record instead of in-process atomics. This is what makes a run analyzable after the
+/// process that ran it has exited, per the original request.
+///
+/// `DbOps` itself (and a "read every attempt back for `run_id`" query over it) isn't defined in
+/// this module -- only the write methods this file already calls (`insert_run_tx`,
+/// `insert_tx_attempt`) are visible here, and neither persists an inclusion timestamp today, so
+/// there's nothing to page `PersistedTxAttempt::included_at_ms` from yet. Adding that read method
+/// and an inclusion-timestamp column is a `DbOps`-side change outside this file; this function is
+/// the consumer that change should feed into, so the rest of the aggregation (including folding
+/// `TxStatus::Replaced` rows into `replaced`, which `MetricsCallback` itself can never do) doesn't
+/// need to be rewritten once it lands. One more gap this inherits from `TxStatus`: it has no
+/// "included but reverted" variant, so every `Included` row folds into `confirmed` here, same as
+/// `ConfirmingCallback` records it today -- distinguishing reverts in the persisted record needs a
+/// `TxStatus` change too.
+pub fn aggregate_persisted_metrics(attempts: &[PersistedTxAttempt]) -> RunMetrics {
+    let metrics = RunMetrics::default();
+    let o = std::sync::atomic::Ordering::Relaxed;
+    for attempt in attempts {
+        metrics.sent.fetch_add(1, o);
+        match attempt.status {
+            TxStatus::Included => {
+                metrics.confirmed.fetch_add(1, o);
+                if let (Some(sent_at_ms), Some(included_at_ms)) =
+                    (attempt.sent_at_ms, attempt.included_at_ms)
+                {
+                    metrics.record_latency_ms(included_at_ms.saturating_sub(sent_at_ms));
+                }
+            }
+            TxStatus::Replaced => {
+                metrics.replaced.fetch_add(1, o);
+            }
+            TxStatus::Dropped => {
+                metrics.dropped.fetch_add(1, o);
+            }
+            TxStatus::Pending => {}
+        }
+    }
+    metrics
+}
+
 #[cfg(test)]
 pub mod tests {
-    use super::TestConfig;
+    use super::{TestConfig, WalletConfig};
     use crate::db::sqlite::SqliteDb;
     use crate::generator::{
         types::{CreateDefinition, FunctionCallDefinition, FuzzParam, PlanType},
@@ -198,6 +1196,7 @@ pub mod tests {
             env: None,
             create: None,
             setup: None,
+            wallets: None,
             spam: vec![FunctionCallDefinition {
                 to: "0x7a250d5630B4cF539739dF2C5dAcb4c659F248DD".to_owned(),
                 from: "0x7a250d5630B4cF539739dF2C5dAcb4c659F248DD".to_owned(),
@@ -240,6 +1239,7 @@ pub mod tests {
             env: None,
             create: None,
             setup: None,
+            wallets: None,
             spam: vec![
                 fn_call("0xbeef", "0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266"),
                 fn_call("0xea75", "0x70997970C51812dc3A010C7d01b50e0d17dc79C8"),
@@ -254,6 +1254,7 @@ pub mod tests {
             env: None,
             create: None,
             spam: None,
+            wallets: None,
             setup: vec![
                 FunctionCallDefinition {
                     to: "0x7a250d5630B4cF539739dF2C5dAcb4c659F2488D".to_owned(),
@@ -304,6 +1305,7 @@ pub mod tests {
             }]),
             spam: None,
             setup: None,
+            wallets: None,
         }
     }
 
@@ -316,9 +1318,18 @@ pub mod tests {
             create: tc_create.create,
             spam: tc_fuzz.spam,
             setup: tc_setup.setup,
+            wallets: None,
         }
     }
 
+    #[test]
+    fn bumps_eip1559_fee_by_minimum_replacement_increment() {
+        use super::bump_eip1559_fee;
+        assert_eq!(bump_eip1559_fee(1_000_000_000), 1_125_000_000);
+        // rounds up so a tiny fee still strictly increases
+        assert_eq!(bump_eip1559_fee(1), 2);
+    }
+
     #[test]
     fn parses_testconfig_toml() {
         let test_file = TestConfig::from_file("univ2ConfigTest.toml").unwrap();
@@ -395,6 +1406,57 @@ pub mod tests {
         assert_eq!(data, "0x022c0d9f00000000000000000000000000000000000000000000000000000000000000010000000000000000000000000000000000000000000000000000000000000002000000000000000000000000111111111111111111111111111111111111111100000000000000000000000000000000000000000000000000000000000000800000000000000000000000000000000000000000000000000000000000000002dead000000000000000000000000000000000000000000000000000000000000");
     }
 
+    #[tokio::test]
+    async fn derived_wallet_pool_feeds_test_scenario_from_set() {
+        // anvil's default mnemonic -- account 0 of this pool is anvil's pre-funded
+        // 0xf39Fd6e5...266, so the spam tx below can be signed and broadcast with no separate
+        // funding step.
+        let wallets = WalletConfig {
+            mnemonic: "test test test test test test test test test test test junk".to_owned(),
+            num_accounts: 1,
+            funding_amount: None,
+            funding_key: None,
+        };
+        let derived = wallets.derive_signers().unwrap();
+
+        let anvil = spawn_anvil();
+        let mut test_file = get_testconfig();
+        test_file.wallets = Some(wallets);
+        test_file.spam = vec![FunctionCallDefinition {
+            to: "0x7a250d5630B4cF539739dF2C5dAcb4c659F248DD".to_owned(),
+            from: derived[0].address().to_string(),
+            signature: "swap(uint256 x, uint256 y, address a, bytes b)".to_owned(),
+            args: vec![
+                "1".to_owned(),
+                "2".to_owned(),
+                Address::repeat_byte(0x11).encode_hex(),
+                "0xdead".to_owned(),
+            ]
+            .into(),
+            fuzz: None,
+            value: None,
+        }]
+        .into();
+
+        // signer_pool is what a caller wiring a configured [wallets] section into a run should
+        // call -- it returns the derived pool here instead of falling back to `fallback`.
+        let signer_pool = test_file.signer_pool(&get_test_signers()).unwrap();
+        assert_eq!(signer_pool, derived);
+
+        let test_gen = TestScenario::new(
+            test_file,
+            SqliteDb::new_memory().into(),
+            anvil.endpoint_url(),
+            RandSeed::new(),
+            &signer_pool,
+        );
+        let spam_txs = test_gen
+            .load_txs(PlanType::Spam(1, |_| Ok(None)))
+            .await
+            .unwrap();
+        assert_eq!(spam_txs[0].tx.from, Some(derived[0].address()));
+    }
+
     #[tokio::test]
     async fn fuzz_is_deterministic() {
         let anvil = spawn_anvil();
@@ -432,4 +1494,165 @@ pub mod tests {
             assert_eq!(data1, data2);
         }
     }
+
+    #[test]
+    fn wallet_derivation_is_deterministic() {
+        let mnemonic = "test test test test test test test test test test test junk".to_owned();
+        let wallets1 = WalletConfig {
+            mnemonic: mnemonic.clone(),
+            num_accounts: 5,
+            funding_amount: None,
+            funding_key: None,
+        };
+        let wallets2 = WalletConfig {
+            mnemonic,
+            num_accounts: 5,
+            funding_amount: None,
+            funding_key: None,
+        };
+
+        let signers1 = wallets1.derive_signers().unwrap();
+        let signers2 = wallets2.derive_signers().unwrap();
+        assert_eq!(signers1.len(), 5);
+        assert_eq!(signers2.len(), 5);
+        for i in 0..signers1.len() {
+            assert_eq!(signers1[i].address(), signers2[i].address());
+        }
+        // every derived account should be distinct from its siblings
+        let unique_addrs = signers1
+            .iter()
+            .map(|s| s.address())
+            .collect::<std::collections::HashSet<_>>();
+        assert_eq!(unique_addrs.len(), signers1.len());
+    }
+
+    #[test]
+    fn replaces_literal_placeholders() {
+        let cfg = get_testconfig();
+        let mut map = HashMap::new();
+        map.insert("world".to_owned(), "earth".to_owned());
+        assert_eq!(
+            cfg.replace_placeholders("hello {world}", &map),
+            "hello earth"
+        );
+    }
+
+    #[test]
+    fn replaces_int_placeholder_with_canonical_decimal() {
+        let cfg = get_testconfig();
+        let mut map = HashMap::new();
+        map.insert("block_number".to_owned(), "0x2a".to_owned());
+        assert_eq!(cfg.replace_placeholders("{int:block_number}", &map), "42");
+    }
+
+    #[test]
+    fn now_placeholder_applies_offset() {
+        let cfg = get_testconfig();
+        let map = HashMap::new();
+        let bare: u64 = cfg.replace_placeholders("{now}", &map).parse().unwrap();
+        let offset: i64 = cfg
+            .replace_placeholders("{now:+30s}", &map)
+            .parse()
+            .unwrap();
+        assert!((offset - bare as i64 - 30).abs() <= 1);
+    }
+
+    #[test]
+    fn random_placeholder_is_deterministic_given_same_seed() {
+        let cfg = get_testconfig();
+        let map = HashMap::new();
+        let seed = RandSeed::from_bytes(&[0x01; 32]);
+        let a = cfg.replace_placeholders_with_seed("{random:uint256}", &map, &seed);
+        let b = cfg.replace_placeholders_with_seed("{random:uint256}", &map, &seed);
+        assert_eq!(a, b);
+
+        let other_seed = RandSeed::from_bytes(&[0x02; 32]);
+        let c = cfg.replace_placeholders_with_seed("{random:uint256}", &map, &other_seed);
+        assert_ne!(a, c);
+    }
+
+    #[test]
+    fn random_placeholder_via_unseeded_trait_method_is_not_tied_to_run_seed() {
+        // `Templater::replace_placeholders` has no way to accept a `RandSeed` (the trait
+        // signature is fixed), so it always resolves `{random:...}` from the same zero seed --
+        // real spam-arg generation must go through `replace_placeholders_with_seed` instead.
+        let cfg = get_testconfig();
+        let map = HashMap::new();
+        let a = cfg.replace_placeholders("{random:uint256}", &map);
+        let b = cfg.replace_placeholders("{random:uint256}", &map);
+        assert_eq!(a, b);
+
+        let run_seed = RandSeed::from_bytes(&[0x01; 32]);
+        let seeded = cfg.replace_placeholders_with_seed("{random:uint256}", &map, &run_seed);
+        assert_ne!(a, seeded);
+    }
+
+    #[tokio::test]
+    async fn watch_file_reloads_on_change() {
+        let path = "watch_test_cargotest.toml";
+        get_testconfig().save_toml(path).unwrap();
+
+        let handle = TestConfig::watch_file(path, std::time::Duration::from_millis(20)).unwrap();
+        assert!(handle.current().await.spam.is_some());
+
+        get_setup_testconfig().save_toml(path).unwrap();
+        // mtime resolution on some filesystems is ~1s; give the watcher time to notice
+        tokio::time::sleep(std::time::Duration::from_secs(1)).await;
+
+        let reloaded = handle.current().await;
+        assert!(reloaded.spam.is_none());
+        assert!(reloaded.setup.is_some());
+
+        handle.stop();
+        fs::remove_file(path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn watch_file_rejects_invalid_reload() {
+        let path = "watch_test_invalid_cargotest.toml";
+        get_testconfig().save_toml(path).unwrap();
+
+        let handle = TestConfig::watch_file(path, std::time::Duration::from_millis(20)).unwrap();
+
+        // an empty config has no create/setup/spam steps and fails validate()
+        fs::write(path, "").unwrap();
+        tokio::time::sleep(std::time::Duration::from_secs(1)).await;
+
+        assert!(handle.current().await.spam.is_some());
+
+        handle.stop();
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn run_metrics_computes_throughput_and_success_rate() {
+        use super::RunMetrics;
+        use std::sync::atomic::Ordering;
+
+        let metrics = RunMetrics::default();
+        metrics.sent.fetch_add(10, Ordering::Relaxed);
+        metrics.confirmed.fetch_add(8, Ordering::Relaxed);
+        metrics.dropped.fetch_add(2, Ordering::Relaxed);
+
+        assert_eq!(metrics.success_rate(), 0.8);
+        assert_eq!(
+            metrics.throughput_tx_per_sec(std::time::Duration::from_secs(5)),
+            2.0
+        );
+    }
+
+    #[test]
+    fn run_metrics_computes_latency_percentiles() {
+        use super::RunMetrics;
+
+        let metrics = RunMetrics::default();
+        assert_eq!(metrics.latency_percentile_ms(0.5), None);
+
+        for latency in [100, 200, 300, 400, 500] {
+            metrics.record_latency_ms(latency);
+        }
+        assert_eq!(metrics.latency_percentile_ms(0.0), Some(100));
+        assert_eq!(metrics.latency_percentile_ms(1.0), Some(500));
+        assert_eq!(metrics.latency_percentile_ms(0.5), Some(300));
+    }
 }